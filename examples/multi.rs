@@ -9,10 +9,15 @@ fn main() -> Result<()> {
 
     println!("Hi my name is Sean Larkin, and here are some of my Rust crates:\n");
 
-    for krate in crates {
-        println!("📦 Name: {}", krate.krate.name);
-        println!("🦀 {}", krate.krate.description);
-        println!("🎉 Latest Version: {}\n", krate.get_latest());
+    for (name, result) in crates {
+        match result {
+            Ok(krate) => {
+                println!("📦 Name: {}", krate.krate.name);
+                println!("🦀 {}", krate.krate.description);
+                println!("🎉 Latest Version: {}\n", krate.get_latest());
+            }
+            Err(e) => println!("❌ {name}: {e}\n"),
+        }
     }
 
     Ok(())
@@ -11,7 +11,7 @@ fn main() {
 
             println!(
                 "Here are the features for version 1.0.107: {:?}",
-                syn_crate.get_features_for_version("1.0.107").unwrap()
+                syn_crate.get_features_for_version("=1.0.107").unwrap()
             )
         }
         Err(e) => println!("Error: {e}"),
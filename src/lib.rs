@@ -1,11 +1,23 @@
 use anyhow::Result;
-use reqwest::{ClientBuilder, Response};
-use serde::Deserialize;
+use bytes::Bytes;
+use flate2::read::GzDecoder;
+use futures::stream::{FuturesUnordered, StreamExt};
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::{ClientBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tar::Archive;
 use thiserror::Error;
 
 const CRATES_IO_URL: &str = "https://crates.io/api/v1/crates";
+const DEFAULT_REGISTRY_HOST: &str = "https://crates.io";
+const CRATES_API_PATH: &str = "api/v1/crates";
 const UNIQUE_USER_AGENT: &str = "krates/0.3.0";
+const CACHE_FILE_NAME: &str = "krate-cache.json";
+const DEFAULT_CONCURRENCY: usize = 4;
 
 #[derive(Error, Debug)]
 enum KrateError {
@@ -13,22 +25,170 @@ enum KrateError {
     KrateNotFound,
     #[error("Server Status Error: {0}")]
     OtherKrateError(reqwest::Error),
+    #[error("No cached entry for `{0}` and the client is in cache_only mode")]
+    CacheMiss(String),
+    #[error("`{0}` is missing a [package] name or version")]
+    InvalidManifest(PathBuf),
+    #[error("`{0}` has no published non-yanked stable release to compare against")]
+    NoStableRelease(String),
+    #[error("`{0}` has no version `{1}` published on the registry")]
+    VersionNotFound(String, String),
+    #[error("the download for `{0}` was not a gzip tarball")]
+    NotATarball(String),
+    #[error("`{0}` v{1} does not contain a README at `{2}`")]
+    ReadmeNotFound(String, String, String),
+}
+
+/// Outcome of comparing a local crate version against the registry, produced by
+/// [`SyncKrateClient::check_up_to_date`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateStatus {
+    /// The local version is the newest non-yanked release (or newer).
+    UpToDate,
+    /// A newer non-yanked release exists on the registry.
+    Outdated { current: String, latest: String },
+}
+
+/// A downloaded `.crate` tarball bundled with the registry's `yanked` flag for
+/// the requested release, so callers can warn before building against a yanked
+/// version. Produced by [`SyncKrateClient::download_crate`].
+#[derive(Debug, Clone)]
+pub struct CrateDownload {
+    /// The raw gzip-compressed tarball bytes as served by the registry.
+    pub bytes: Bytes,
+    /// Whether the requested version has been yanked from the registry.
+    pub yanked: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoManifest {
+    package: Option<ManifestPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ManifestPackage {
+    name: Option<String>,
+    version: Option<String>,
+}
+
+/// Read the `[package]` `name` and `version` from a local `Cargo.toml`.
+fn read_package_identity(path: &Path) -> anyhow::Result<(String, String)> {
+    let contents = std::fs::read_to_string(path)?;
+    let manifest: CargoManifest = toml::from_str(&contents)?;
+
+    match manifest.package {
+        Some(ManifestPackage {
+            name: Some(name),
+            version: Some(version),
+        }) => Ok((name, version)),
+        _ => Err(KrateError::InvalidManifest(path.to_path_buf()).into()),
+    }
+}
+
+/// Compare the manifest's `current` version against the newest non-yanked
+/// stable release of `krate`.
+fn compare_against_latest(krate: &Krate, current: &str) -> anyhow::Result<UpdateStatus> {
+    let current_version = semver::Version::parse(current)?;
+    let latest = krate
+        .resolve_version("*")
+        .ok_or_else(|| KrateError::NoStableRelease(krate.krate.name.clone()))?;
+    let latest_version = semver::Version::parse(&latest.num)?;
+
+    if latest_version > current_version {
+        Ok(UpdateStatus::Outdated {
+            current: current.to_string(),
+            latest: latest.num.clone(),
+        })
+    } else {
+        Ok(UpdateStatus::UpToDate)
+    }
+}
+
+/// A single cached crate payload, keyed by crate name in [`KrateCache`].
+///
+/// The `etag` is replayed as an `If-None-Match` header on the next request so
+/// the registry can answer with `304 Not Modified` when the stored `payload`
+/// (the raw JSON body) is still current.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    etag: Option<String>,
+    payload: String,
+}
+
+/// Two-tier on-disk response cache shared by the sync and async clients.
+///
+/// Entries live in memory for the lifetime of the client and are flushed to
+/// `<cache_dir>/krate-cache.json` on [`save`](KrateCache::save) or when the
+/// owning client is dropped. When `cache_only` is set the cache is authoritative
+/// and the network is never touched.
+#[derive(Debug, Default)]
+struct KrateCache {
+    dir: PathBuf,
+    cache_only: bool,
+    dirty: bool,
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl KrateCache {
+    fn load(dir: PathBuf, cache_only: bool) -> KrateCache {
+        let entries = std::fs::read_to_string(dir.join(CACHE_FILE_NAME))
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+
+        KrateCache {
+            dir,
+            cache_only,
+            dirty: false,
+            entries,
+        }
+    }
+
+    /// Flush the in-memory entries to disk, creating the cache directory if it
+    /// does not yet exist. A no-op when nothing has changed since the last save.
+    fn save(&mut self) -> anyhow::Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        std::fs::create_dir_all(&self.dir)?;
+        let contents = serde_json::to_string(&self.entries)?;
+        std::fs::write(self.dir.join(CACHE_FILE_NAME), contents)?;
+        self.dirty = false;
+        Ok(())
+    }
 }
 
 impl Krate {
+    /// Resolve the highest non-yanked version that satisfies a semver
+    /// requirement such as `^1.0`, `=1.2.3`, or `1`. Yanked releases are always
+    /// skipped, and — because a bare `*` requirement excludes pre-releases —
+    /// so are pre-releases unless the requirement opts into them. Returns
+    /// `None` when `req` is not a valid [`semver::VersionReq`] or nothing
+    /// matches.
+    pub fn resolve_version(&self, req: &str) -> Option<&KrateVersion> {
+        let req = semver::VersionReq::parse(req).ok()?;
+        self.versions
+            .iter()
+            .filter(|v| !v.yanked)
+            .filter_map(|v| semver::Version::parse(&v.num).ok().map(|parsed| (parsed, v)))
+            .filter(|(parsed, _)| req.matches(parsed))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, v)| v)
+    }
+
     pub fn get_latest(&self) -> String {
-        String::from(&self.versions[0].num)
+        self.resolve_version("*")
+            .map(|v| v.num.clone())
+            .unwrap_or_else(|| String::from(&self.versions[0].num))
     }
 
+    /// Return the feature table of the highest non-yanked version satisfying
+    /// `version`, which is interpreted as a [`semver::VersionReq`] — so a bare
+    /// `"1.0.107"` behaves like `"^1.0.107"` and may resolve to a newer `1.x`.
+    /// Pass `"=1.0.107"` to pin an exact release.
     pub fn get_features_for_version(&self, version: &str) -> Option<&HashMap<String, Vec<String>>> {
-        for v in &self.versions {
-            if v.num == version {
-                if let Some(features) = &v.features {
-                    return Some(features);
-                }
-            }
-        }
-        None
+        self.resolve_version(version)?.features.as_ref()
     }
 }
 
@@ -84,6 +244,29 @@ pub struct KrateMetadata {
     pub versions: Vec<i32>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct KrateOwner {
+    pub id: i64,
+    pub login: String,
+    pub name: Option<String>,
+    pub kind: Option<String>,
+    pub avatar: Option<String>,
+}
+
+/// The `/owners` endpoint wraps the list of owners in a `users` field.
+#[derive(Debug, Deserialize)]
+struct OwnersResponse {
+    users: Vec<KrateOwner>,
+}
+
+/// Crate metadata bundled with its owners, returned by
+/// [`SyncKrateClient::get_full`].
+#[derive(Debug)]
+pub struct KrateFull {
+    pub krate: Krate,
+    pub owners: Vec<KrateOwner>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct KrateKeyword {
     pub crates_cnt: i64,
@@ -95,61 +278,426 @@ pub struct KrateKeyword {
 #[derive(Debug)]
 pub struct SyncKrateClient {
     client: reqwest::blocking::Client,
+    cache: Option<Mutex<KrateCache>>,
+    base_url: String,
 }
 
 #[derive(Debug)]
 pub struct AsyncKrateClient {
     client: reqwest::Client,
+    cache: Option<Mutex<KrateCache>>,
+    semaphore: Arc<tokio::sync::Semaphore>,
+    base_url: String,
 }
 
 impl SyncKrateClient {
     pub fn get(&self, crate_name: &str) -> anyhow::Result<Krate> {
-        let url = format!("{CRATES_IO_URL}/{crate_name}");
+        let url = format!("{}/{crate_name}", self.base_url);
+
+        let Some(cache) = &self.cache else {
+            let res = self.client.get(url).send()?;
+            return match res.error_for_status() {
+                Ok(res) => Ok(res.json()?),
+                Err(e) => Err(handle_error(e).into()),
+            };
+        };
+
+        let mut cache = cache.lock().unwrap();
+
+        if cache.cache_only {
+            return match cache.entries.get(crate_name) {
+                Some(entry) => Ok(serde_json::from_str(&entry.payload)?),
+                None => Err(KrateError::CacheMiss(crate_name.to_string()).into()),
+            };
+        }
+
+        let mut request = self.client.get(url);
+        if let Some(etag) = cache.entries.get(crate_name).and_then(|e| e.etag.clone()) {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        let res = request.send()?;
+        if res.status() == StatusCode::NOT_MODIFIED {
+            let entry = cache
+                .entries
+                .get(crate_name)
+                .expect("304 implies a stored entry");
+            return Ok(serde_json::from_str(&entry.payload)?);
+        }
 
-        let res = self.client.get(url).send()?;
         match res.error_for_status() {
             Ok(res) => {
-                let krate: Krate = res.json()?;
+                let etag = extract_etag(res.headers());
+                let payload = res.text()?;
+                let krate: Krate = serde_json::from_str(&payload)?;
+                cache
+                    .entries
+                    .insert(crate_name.to_string(), CacheEntry { etag, payload });
+                cache.dirty = true;
                 Ok(krate)
             }
             Err(e) => Err(handle_error(e).into()),
         }
     }
+
+    /// Flush the response cache to disk. A no-op when caching is disabled.
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(cache) = &self.cache {
+            cache.lock().unwrap().save()?;
+        }
+        Ok(())
+    }
+
+    /// Look up several crates in one call. The blocking client fetches them
+    /// sequentially; each crate carries its own `Result` so a single failure
+    /// does not abort the batch. See [`AsyncKrateClient::get_multi_async`] for
+    /// the concurrent variant.
+    pub fn get_multi(&self, crate_names: Vec<&str>) -> Vec<(String, anyhow::Result<Krate>)> {
+        crate_names
+            .into_iter()
+            .map(|name| (name.to_string(), self.get(name)))
+            .collect()
+    }
+
+    /// Parse a local `Cargo.toml`, look its crate up on the registry, and
+    /// report whether the manifest's version is the newest non-yanked stable
+    /// release. Errors when the manifest lacks a `[package]` name/version or
+    /// the crate is absent from the registry.
+    pub fn check_up_to_date<P: AsRef<Path>>(
+        &self,
+        manifest_path: P,
+    ) -> anyhow::Result<UpdateStatus> {
+        let (name, current) = read_package_identity(manifest_path.as_ref())?;
+        let krate = self.get(&name)?;
+        compare_against_latest(&krate, &current)
+    }
+
+    /// Fetch and validate the gzip `.crate` tarball for `version`, bundling it
+    /// with that release's `yanked` flag. Errors when the version is unknown or
+    /// the registry does not answer with a gzip tarball.
+    pub fn download_crate(&self, name: &str, version: &str) -> anyhow::Result<CrateDownload> {
+        let krate = self.get(name)?;
+        let yanked = find_version(&krate, name, version)?.yanked;
+        let bytes = self.fetch_download(name, version)?;
+        Ok(CrateDownload { bytes, yanked })
+    }
+
+    /// Download `version` of `name` and return the text of its README, as named
+    /// by the version's [`readme_path`](KrateVersion::readme_path).
+    pub fn get_readme(&self, name: &str, version: &str) -> anyhow::Result<String> {
+        let krate = self.get(name)?;
+        let readme_path = find_version(&krate, name, version)?.readme_path.clone();
+        let bytes = self.fetch_download(name, version)?;
+        extract_readme(&bytes, &readme_path)?.ok_or_else(|| {
+            KrateError::ReadmeNotFound(name.to_string(), version.to_string(), readme_path).into()
+        })
+    }
+
+    /// Hit the `download` endpoint and return the validated tarball bytes.
+    fn fetch_download(&self, name: &str, version: &str) -> anyhow::Result<Bytes> {
+        let url = format!("{}/{name}/{version}/download", self.base_url);
+        let res = self.client.get(url).send()?;
+        let bytes = match res.error_for_status() {
+            Ok(res) => res.bytes()?,
+            Err(e) => return Err(handle_error(e).into()),
+        };
+        ensure_gzip(name, &bytes)?;
+        Ok(bytes)
+    }
+
+    /// Fetch the list of owners for `name` from the `/owners` endpoint.
+    pub fn get_owners(&self, name: &str) -> anyhow::Result<Vec<KrateOwner>> {
+        let url = format!("{}/{name}/owners", self.base_url);
+        let res = self.client.get(url).send()?;
+        match res.error_for_status() {
+            Ok(res) => Ok(res.json::<OwnersResponse>()?.users),
+            Err(e) => Err(handle_error(e).into()),
+        }
+    }
+
+    /// Fetch a crate's metadata and its owners together. The blocking client
+    /// issues the two requests sequentially; see [`AsyncKrateClient::get_full`]
+    /// for the parallel variant.
+    pub fn get_full(&self, name: &str) -> anyhow::Result<KrateFull> {
+        let krate = self.get(name)?;
+        let owners = self.get_owners(name)?;
+        Ok(KrateFull { krate, owners })
+    }
+}
+
+impl Drop for SyncKrateClient {
+    fn drop(&mut self) {
+        if let Some(cache) = &self.cache {
+            let _ = cache.lock().unwrap().save();
+        }
+    }
 }
 
 impl AsyncKrateClient {
     pub async fn get_async(&self, crate_name: &str) -> anyhow::Result<Krate> {
-        let url = format!("{CRATES_IO_URL}/{crate_name}");
-        let res: Response = self.client.get(url).send().await?;
+        let url = format!("{}/{crate_name}", self.base_url);
+
+        let Some(cache) = &self.cache else {
+            let res: Response = self.client.get(url).send().await?;
+            return match res.error_for_status() {
+                Ok(res) => Ok(res.json().await?),
+                Err(e) => Err(handle_error(e).into()),
+            };
+        };
+
+        // Only the stored etag is needed across the await point; hold the lock
+        // briefly rather than over the network round-trip.
+        let stored_etag = {
+            let cache = cache.lock().unwrap();
+            if cache.cache_only {
+                return match cache.entries.get(crate_name) {
+                    Some(entry) => Ok(serde_json::from_str(&entry.payload)?),
+                    None => Err(KrateError::CacheMiss(crate_name.to_string()).into()),
+                };
+            }
+            cache.entries.get(crate_name).and_then(|e| e.etag.clone())
+        };
+
+        let mut request = self.client.get(url);
+        if let Some(etag) = stored_etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        let res: Response = request.send().await?;
+        if res.status() == StatusCode::NOT_MODIFIED {
+            let cache = cache.lock().unwrap();
+            let entry = cache
+                .entries
+                .get(crate_name)
+                .expect("304 implies a stored entry");
+            return Ok(serde_json::from_str(&entry.payload)?);
+        }
 
         match res.error_for_status() {
             Ok(res) => {
-                let krate: Krate = res.json().await?;
+                let etag = extract_etag(res.headers());
+                let payload = res.text().await?;
+                let krate: Krate = serde_json::from_str(&payload)?;
+                let mut cache = cache.lock().unwrap();
+                cache
+                    .entries
+                    .insert(crate_name.to_string(), CacheEntry { etag, payload });
+                cache.dirty = true;
                 Ok(krate)
             }
             Err(e) => Err(handle_error(e).into()),
         }
     }
+
+    /// Flush the response cache to disk. A no-op when caching is disabled.
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(cache) = &self.cache {
+            cache.lock().unwrap().save()?;
+        }
+        Ok(())
+    }
+
+    /// Look up several crates concurrently, capping in-flight requests at the
+    /// client's configured concurrency bound (default 4) via the client-wide
+    /// [`tokio::sync::Semaphore`] so crates.io is never hammered. The same
+    /// budget is shared with [`get_full`](Self::get_full). Results are returned
+    /// as `(name, Result)` pairs — a failed crate surfaces its error without
+    /// sinking the rest of the batch.
+    pub async fn get_multi_async(
+        &self,
+        crate_names: Vec<&str>,
+    ) -> Vec<(String, anyhow::Result<Krate>)> {
+        let mut in_flight = crate_names
+            .into_iter()
+            .map(|name| async {
+                let _permit = self
+                    .semaphore
+                    .acquire()
+                    .await
+                    .expect("semaphore is never closed");
+                (name.to_string(), self.get_async(name).await)
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        let mut results = Vec::with_capacity(in_flight.len());
+        while let Some(result) = in_flight.next().await {
+            results.push(result);
+        }
+        results
+    }
+
+    /// Async sibling of [`SyncKrateClient::check_up_to_date`].
+    pub async fn check_up_to_date_async<P: AsRef<Path>>(
+        &self,
+        manifest_path: P,
+    ) -> anyhow::Result<UpdateStatus> {
+        let (name, current) = read_package_identity(manifest_path.as_ref())?;
+        let krate = self.get_async(&name).await?;
+        compare_against_latest(&krate, &current)
+    }
+
+    /// Async sibling of [`SyncKrateClient::download_crate`].
+    pub async fn download_crate(
+        &self,
+        name: &str,
+        version: &str,
+    ) -> anyhow::Result<CrateDownload> {
+        let krate = self.get_async(name).await?;
+        let yanked = find_version(&krate, name, version)?.yanked;
+        let bytes = self.fetch_download(name, version).await?;
+        Ok(CrateDownload { bytes, yanked })
+    }
+
+    /// Async sibling of [`SyncKrateClient::get_readme`].
+    pub async fn get_readme(&self, name: &str, version: &str) -> anyhow::Result<String> {
+        let krate = self.get_async(name).await?;
+        let readme_path = find_version(&krate, name, version)?.readme_path.clone();
+        let bytes = self.fetch_download(name, version).await?;
+        extract_readme(&bytes, &readme_path)?.ok_or_else(|| {
+            KrateError::ReadmeNotFound(name.to_string(), version.to_string(), readme_path).into()
+        })
+    }
+
+    /// Hit the `download` endpoint and return the validated tarball bytes.
+    async fn fetch_download(&self, name: &str, version: &str) -> anyhow::Result<Bytes> {
+        let url = format!("{}/{name}/{version}/download", self.base_url);
+        let res: Response = self.client.get(url).send().await?;
+        let bytes = match res.error_for_status() {
+            Ok(res) => res.bytes().await?,
+            Err(e) => return Err(handle_error(e).into()),
+        };
+        ensure_gzip(name, &bytes)?;
+        Ok(bytes)
+    }
+
+    /// Async sibling of [`SyncKrateClient::get_owners`].
+    pub async fn get_owners(&self, name: &str) -> anyhow::Result<Vec<KrateOwner>> {
+        let url = format!("{}/{name}/owners", self.base_url);
+        let res: Response = self.client.get(url).send().await?;
+        match res.error_for_status() {
+            Ok(res) => Ok(res.json::<OwnersResponse>().await?.users),
+            Err(e) => Err(handle_error(e).into()),
+        }
+    }
+
+    /// Fetch a crate's metadata and its owners in parallel with
+    /// [`futures::join!`], bundling both into a [`KrateFull`]. Each request
+    /// draws a permit from the same client-wide concurrency budget as
+    /// [`get_multi_async`](Self::get_multi_async).
+    pub async fn get_full(&self, name: &str) -> anyhow::Result<KrateFull> {
+        let krate = async {
+            let _permit = self
+                .semaphore
+                .acquire()
+                .await
+                .expect("semaphore is never closed");
+            self.get_async(name).await
+        };
+        let owners = async {
+            let _permit = self
+                .semaphore
+                .acquire()
+                .await
+                .expect("semaphore is never closed");
+            self.get_owners(name).await
+        };
+
+        let (krate, owners) = futures::join!(krate, owners);
+        Ok(KrateFull {
+            krate: krate?,
+            owners: owners?,
+        })
+    }
+}
+
+impl Drop for AsyncKrateClient {
+    fn drop(&mut self) {
+        if let Some(cache) = &self.cache {
+            let _ = cache.lock().unwrap().save();
+        }
+    }
 }
 
 pub struct KrateClientBuilder {
     user_agent: String,
+    cache_dir: Option<PathBuf>,
+    cache_only: bool,
+    concurrency: usize,
+    registry_host: String,
 }
 
 impl KrateClientBuilder {
     pub fn new(user_agent: &str) -> KrateClientBuilder {
         KrateClientBuilder {
             user_agent: user_agent.to_string(),
+            cache_dir: None,
+            cache_only: false,
+            concurrency: DEFAULT_CONCURRENCY,
+            registry_host: DEFAULT_REGISTRY_HOST.to_string(),
         }
     }
 
-    pub fn build_sync(&self) -> anyhow::Result<SyncKrateClient> {
+    /// Target an alternative registry that exposes the same `/api/v1/crates`
+    /// shape as crates.io — a mirror, a self-hosted instance, or a local test
+    /// server. Defaults to `https://crates.io`.
+    pub fn registry_host(mut self, url: &str) -> KrateClientBuilder {
+        self.registry_host = url.to_string();
+        self
+    }
+
+    fn base_url(&self) -> String {
+        format!("{}/{CRATES_API_PATH}", self.registry_host.trim_end_matches('/'))
+    }
+
+    /// Cap the number of in-flight requests [`AsyncKrateClient::get_multi_async`]
+    /// issues at once. Defaults to 4 to stay within crates.io's rate limits.
+    pub fn concurrency(mut self, concurrency: usize) -> KrateClientBuilder {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Persist responses under `path` and consult them before hitting the
+    /// network. Enabling a cache directory is what turns caching on.
+    pub fn cache_dir<P: AsRef<Path>>(mut self, path: P) -> KrateClientBuilder {
+        self.cache_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Serve lookups exclusively from the on-disk cache, never touching the
+    /// network. Missing keys error with [`KrateError::CacheMiss`]. Implies a
+    /// cache directory; pair with [`cache_dir`](Self::cache_dir).
+    pub fn cache_only(mut self, cache_only: bool) -> KrateClientBuilder {
+        self.cache_only = cache_only;
+        self
+    }
+
+    fn build_cache(&self) -> Option<Mutex<KrateCache>> {
+        self.cache_dir
+            .clone()
+            .map(|dir| Mutex::new(KrateCache::load(dir, self.cache_only)))
+    }
+
+    /// `cache_only` without a `cache_dir` would silently fall back to the
+    /// network, defeating offline mode — reject that combination up front.
+    fn validate(&self) -> anyhow::Result<()> {
         if has_empty_user_agent(&self.user_agent) {
             return Err(anyhow::anyhow!(
                 "User Agent must be a string with at least one character"
             ));
         }
 
+        if self.cache_only && self.cache_dir.is_none() {
+            return Err(anyhow::anyhow!(
+                "cache_only requires a cache_dir to read entries from"
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn build_sync(&self) -> anyhow::Result<SyncKrateClient> {
+        self.validate()?;
+
         let operator_user_agent = format!(
             "{} - Brought to you by: {UNIQUE_USER_AGENT}",
             self.user_agent
@@ -159,15 +707,15 @@ impl KrateClientBuilder {
             .user_agent(&operator_user_agent)
             .build()?;
 
-        return Ok(SyncKrateClient { client: client });
+        Ok(SyncKrateClient {
+            client,
+            cache: self.build_cache(),
+            base_url: self.base_url(),
+        })
     }
 
     pub fn build_asnyc(&self) -> anyhow::Result<AsyncKrateClient> {
-        if has_empty_user_agent(&self.user_agent) {
-            return Err(anyhow::anyhow!(
-                "User Agent must be a string with at least one character"
-            ));
-        }
+        self.validate()?;
 
         let operator_user_agent = format!(
             "{} - Brought to you by: {UNIQUE_USER_AGENT}",
@@ -178,8 +726,67 @@ impl KrateClientBuilder {
             .user_agent(&operator_user_agent)
             .build()?;
 
-        return Ok(AsyncKrateClient { client: client });
+        Ok(AsyncKrateClient {
+            client,
+            cache: self.build_cache(),
+            semaphore: Arc::new(tokio::sync::Semaphore::new(self.concurrency)),
+            base_url: self.base_url(),
+        })
+    }
+}
+
+/// The magic bytes every gzip stream begins with.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Locate a specific published version within a crate's version list.
+fn find_version<'a>(
+    krate: &'a Krate,
+    name: &str,
+    version: &str,
+) -> anyhow::Result<&'a KrateVersion> {
+    krate
+        .versions
+        .iter()
+        .find(|v| v.num == version)
+        .ok_or_else(|| KrateError::VersionNotFound(name.to_string(), version.to_string()).into())
+}
+
+/// Reject anything that is not a gzip tarball before we try to inflate it — a
+/// registry that answers `download` with HTML or JSON would otherwise blow up
+/// deep inside the decoder.
+fn ensure_gzip(name: &str, bytes: &[u8]) -> anyhow::Result<()> {
+    if bytes.len() < GZIP_MAGIC.len() || bytes[..GZIP_MAGIC.len()] != GZIP_MAGIC {
+        return Err(KrateError::NotATarball(name.to_string()).into());
+    }
+    Ok(())
+}
+
+/// Gunzip and untar `bytes` in memory, returning the contents of the archive
+/// entry whose path ends with `readme_path` (`None` when no such entry exists).
+fn extract_readme(bytes: &[u8], readme_path: &str) -> anyhow::Result<Option<String>> {
+    // An empty path is a suffix of every archive entry, which would hand back
+    // the first file as if it were the README — treat it as "no README".
+    if readme_path.is_empty() {
+        return Ok(None);
+    }
+
+    let mut archive = Archive::new(GzDecoder::new(bytes));
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if entry.path()?.ends_with(readme_path) {
+            let mut contents = String::new();
+            entry.read_to_string(&mut contents)?;
+            return Ok(Some(contents));
+        }
     }
+    Ok(None)
+}
+
+fn extract_etag(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
 }
 
 fn handle_error(e: reqwest::Error) -> KrateError {
@@ -212,6 +819,19 @@ pub fn get(crate_name: &str, user_agent: &str) -> Result<Krate> {
     }
 }
 
+/// Fetch several crates concurrently with a default-built client, returning a
+/// `(name, Result)` pair per crate. A convenience wrapper over
+/// [`AsyncKrateClient::get_multi_async`] for callers that are not already inside
+/// an async runtime.
+pub fn get_multi(
+    crate_names: Vec<&str>,
+    user_agent: &str,
+) -> Result<Vec<(String, Result<Krate>)>> {
+    let client = KrateClientBuilder::new(user_agent).build_asnyc()?;
+    let runtime = tokio::runtime::Runtime::new()?;
+    Ok(runtime.block_on(client.get_multi_async(crate_names)))
+}
+
 pub async fn get_async(crate_name: &str, user_agent: &str) -> Result<Krate> {
     // Enforce a string with actual characters in it
     if has_empty_user_agent(user_agent) {
@@ -307,14 +927,135 @@ mod tests {
     #[test]
     fn test_get_features_for_version() {
         let krate: Krate = get_sync_krate_client().get("tokio").unwrap();
-        let features = krate.get_features_for_version("1.24.2");
+        let features = krate.get_features_for_version("=1.24.2");
         assert_eq!(features.unwrap().len(), 15);
     }
 
+    #[test]
+    fn test_resolve_version_matches_range() {
+        let krate: Krate = get_sync_krate_client().get("tokio").unwrap();
+        let resolved = krate.resolve_version("^1.24").unwrap();
+        assert!(resolved.num.starts_with("1."));
+        assert!(!resolved.yanked);
+    }
+
     #[test]
     fn test_get_features_for_wrong_version() {
         let krate: Krate = get_sync_krate_client().get("cargo-outdated").unwrap();
         let features = krate.get_features_for_version("9999.0.00");
         assert!(features.is_none());
     }
+
+    /// A minimal but complete crate payload used by the offline cache tests so
+    /// they never have to touch the network.
+    const DEMO_KRATE_JSON: &str = r#"{"categories":[],"versions":[],"crate":{"categories":[],"created_at":"","description":"a demo crate","documentation":null,"downloads":0,"exact_match":false,"homepage":null,"id":"demo","keywords":[],"max_version":"1.0.0","max_stable_version":"1.0.0","name":"demo","newest_version":"1.0.0","recent_downloads":0,"repository":"","updated_at":"","versions":[]},"keywords":null}"#;
+
+    #[test]
+    fn test_cache_only_errors_on_missing_entry() {
+        let dir = std::env::temp_dir().join("krate-cache-test-miss");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let client = client_builder()
+            .cache_dir(&dir)
+            .cache_only(true)
+            .build_sync()
+            .unwrap();
+
+        let err = client.get("demo").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No cached entry for `demo` and the client is in cache_only mode"
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_cache_only_returns_seeded_payload() {
+        let dir = std::env::temp_dir().join("krate-cache-test-seed");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let entries = serde_json::json!({
+            "demo": { "etag": serde_json::Value::Null, "payload": DEMO_KRATE_JSON }
+        });
+        std::fs::write(dir.join(CACHE_FILE_NAME), entries.to_string()).unwrap();
+
+        let client = client_builder()
+            .cache_dir(&dir)
+            .cache_only(true)
+            .build_sync()
+            .unwrap();
+
+        let krate = client.get("demo").unwrap();
+        assert_eq!(krate.krate.name, "demo");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_cache_only_without_cache_dir_errors() {
+        let builder = client_builder().cache_only(true).build_sync();
+        assert_eq!(
+            builder.err().unwrap().to_string(),
+            "cache_only requires a cache_dir to read entries from"
+        );
+    }
+
+    /// A crate payload carrying two non-yanked releases, used by the offline
+    /// `check_up_to_date` comparison tests.
+    const VERSIONED_KRATE_JSON: &str = r#"{"categories":[],"versions":[{"crate_size":null,"license":null,"num":"1.0.0","readme_path":"README.md","yanked":false,"features":null,"id":1},{"crate_size":null,"license":null,"num":"1.2.0","readme_path":"README.md","yanked":false,"features":null,"id":2}],"crate":{"categories":[],"created_at":"","description":"a demo crate","documentation":null,"downloads":0,"exact_match":false,"homepage":null,"id":"demo","keywords":[],"max_version":"1.2.0","max_stable_version":"1.2.0","name":"demo","newest_version":"1.2.0","recent_downloads":0,"repository":"","updated_at":"","versions":[1,2]},"keywords":null}"#;
+
+    #[test]
+    fn test_compare_against_latest_outdated() {
+        let krate: Krate = serde_json::from_str(VERSIONED_KRATE_JSON).unwrap();
+        assert_eq!(
+            compare_against_latest(&krate, "1.0.0").unwrap(),
+            UpdateStatus::Outdated {
+                current: "1.0.0".to_string(),
+                latest: "1.2.0".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_compare_against_latest_up_to_date() {
+        let krate: Krate = serde_json::from_str(VERSIONED_KRATE_JSON).unwrap();
+        assert_eq!(
+            compare_against_latest(&krate, "1.2.0").unwrap(),
+            UpdateStatus::UpToDate
+        );
+    }
+
+    #[test]
+    fn test_read_package_identity_reads_name_and_version() {
+        let dir = std::env::temp_dir().join("krate-manifest-ok");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("Cargo.toml");
+        std::fs::write(&path, "[package]\nname = \"demo\"\nversion = \"1.0.0\"\n").unwrap();
+
+        let (name, version) = read_package_identity(&path).unwrap();
+        assert_eq!(name, "demo");
+        assert_eq!(version, "1.0.0");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_read_package_identity_missing_version_errors() {
+        let dir = std::env::temp_dir().join("krate-manifest-bad");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("Cargo.toml");
+        std::fs::write(&path, "[package]\nname = \"demo\"\n").unwrap();
+
+        let err = read_package_identity(&path).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("missing a [package] name or version"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }